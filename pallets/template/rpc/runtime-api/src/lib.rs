@@ -0,0 +1,24 @@
+//! Runtime API for `pallet-currency`.
+//!
+//! Lets an RPC server (see `pallet-currency-rpc`) query balances and issuance straight from
+//! the runtime instead of having to decode pallet storage by hand.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use polkadot_sdk::sp_api;
+
+sp_api::decl_runtime_apis! {
+    /// Read-only balance and issuance queries for `pallet-currency`.
+    pub trait CurrencyApi<AssetId, AccountId, Balance>
+    where
+        AssetId: codec::Codec,
+        AccountId: codec::Codec,
+        Balance: codec::Codec,
+    {
+        /// The free balance of `account` in `asset`.
+        fn free_balance(asset: AssetId, account: AccountId) -> Balance;
+
+        /// The total issuance of `asset`.
+        fn total_issuance(asset: AssetId) -> Balance;
+    }
+}
@@ -18,72 +18,488 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
     pub type Balance = u128;
 
+    /// The on-chain metadata of a single asset, as set by `create_asset`.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct AssetMetadata {
+        pub name: BoundedVec<u8, ConstU32<50>>,
+        pub symbol: BoundedVec<u8, ConstU32<10>>,
+        pub decimals: u8,
+        pub min_balance: Option<Balance>,
+    }
+
+    /// The compliance state of an account, as set by `set_kyc`.
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum KycStatus {
+        Pending,
+        Approved,
+        Revoked,
+    }
+
+    /// Why an account's funds are held via `hold`/`release`.
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum HoldReason {
+        Staking,
+        Governance,
+        Other,
+    }
+
 	#[pallet::config]
 	pub trait Config: polkadot_sdk::frame_system::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as polkadot_sdk::frame_system::Config>::RuntimeEvent>;
 
+        /// Identifies one asset in the registry.
+        type AssetId: Parameter + Member + Copy + MaxEncodedLen;
+
+        /// Privileged origin allowed to call `set_kyc`.
+        type KycAdmin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Upper bound on the number of distinct `HoldReason`s an account may have open at once.
+        #[pallet::constant]
+        type MaxHolds: Get<u32>;
+
+        /// Fallback minimum mint amount for assets created without a `min_balance`.
         fn min_amount() -> Balance;
+
+        /// The account that collects the fee taken out of every `transfer`.
+        fn treasury_account() -> Self::AccountId;
+
+        /// The fraction of every `transfer` that is routed to `treasury_account()`.
+        fn fee_rate() -> Permill;
+
+        /// When `true`, `mint_unsafe` and `transfer` require both parties to hold
+        /// `KycStatus::Approved`.
+        fn kyc_enforced() -> bool;
+
+        /// The minimum balance an account may hold. Dropping below it (without reaching zero)
+        /// reaps the account, routing the dust to `treasury_account()`, unless the account has
+        /// open `Holds`.
+        fn existential_deposit() -> Balance;
+
+        /// Minimum number of blocks an account must wait between two `faucet` calls.
+        fn faucet_cooldown() -> BlockNumberFor<Self>;
+
+        /// The amount minted by a single `faucet` call.
+        fn faucet_amount() -> Balance;
     }
 
   	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-        Mint { to: T::AccountId, amount: Balance },
-        Transfer { from: T::AccountId, to: T::AccountId, amount: Balance },
+        AssetCreated { asset_id: T::AssetId },
+        Mint { asset_id: T::AssetId, to: T::AccountId, amount: Balance },
+        Transfer { asset_id: T::AssetId, from: T::AccountId, to: T::AccountId, amount: Balance },
+        Burn { asset_id: T::AssetId, from: T::AccountId, amount: Balance },
+        KycUpdated { who: T::AccountId, status: KycStatus },
+        Held { asset_id: T::AssetId, who: T::AccountId, reason: HoldReason, amount: Balance },
+        Released { asset_id: T::AssetId, who: T::AccountId, reason: HoldReason, amount: Balance },
+        Approval { asset_id: T::AssetId, owner: T::AccountId, spender: T::AccountId, amount: Balance },
     }
 
     #[pallet::error]
-    pub enum Error<T> { 
+    pub enum Error<T> {
         InsufficientBalance,
         NonExistentAccount,
-        BelowMinAmount
+        BelowMinAmount,
+        UnknownAsset,
+        AssetAlreadyExists,
+        BadMetadata,
+        NotKycApproved,
+        ExistentialDeposit,
+        TooManyHolds,
+        FaucetCooldown,
+        /// `transfer_from` was asked to move more than `approve` has granted the caller.
+        InsufficientAllowance,
     }
 
     #[pallet::storage]
-    pub type TotalIssuance<T: Config> = StorageValue<_, Balance>;
+    pub type AssetInfo<T: Config> = StorageMap<Key = T::AssetId, Value = AssetMetadata>;
+    #[pallet::storage]
+    pub type TotalIssuance<T: Config> = StorageMap<Key = T::AssetId, Value = Balance>;
     #[pallet::storage]
-    pub type BalanceOf<T: Config> = StorageMap<Key = T::AccountId, Value = Balance>;
+    pub type BalanceOf<T: Config> =
+        StorageDoubleMap<Key1 = T::AssetId, Key2 = T::AccountId, Value = Balance>;
+    /// Cumulative fees and reaped dust routed to `Config::treasury_account()` since genesis,
+    /// per asset.
+    #[pallet::storage]
+    pub type TreasuryBalance<T: Config> = StorageMap<Key = T::AssetId, Value = Balance>;
+    #[pallet::storage]
+    pub type Kyc<T: Config> = StorageMap<Key = T::AccountId, Value = KycStatus>;
+    /// Per-asset, per-account holds. `transfer` and `burn` may only move the portion of
+    /// `BalanceOf` that isn't listed here.
+    #[pallet::storage]
+    pub type Holds<T: Config> = StorageMap<
+        Key = (T::AssetId, T::AccountId),
+        Value = BoundedVec<(HoldReason, Balance), T::MaxHolds>,
+    >;
+    /// `(asset, owner, spender) -> amount` the owner has approved the spender to move via
+    /// `transfer_from`.
+    #[pallet::storage]
+    pub type Allowances<T: Config> =
+        StorageMap<Key = (T::AssetId, T::AccountId, T::AccountId), Value = Balance>;
+    /// `(asset, account) -> block` of that account's last successful `faucet` call.
+    #[pallet::storage]
+    pub type LastFaucet<T: Config> =
+        StorageMap<Key = (T::AssetId, T::AccountId), Value = BlockNumberFor<T>>;
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
+        /// Register a new asset in the registry. `asset_id` must not already exist.
+        pub fn create_asset(
+            origin: T::RuntimeOrigin,
+            asset_id: T::AssetId,
+            name: Vec<u8>,
+            symbol: Vec<u8>,
+            decimals: u8,
+            min_balance: Option<Balance>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            ensure!(!AssetInfo::<T>::contains_key(asset_id), Error::<T>::AssetAlreadyExists);
+
+            let name = BoundedVec::try_from(name).map_err(|_| Error::<T>::BadMetadata)?;
+            let symbol = BoundedVec::try_from(symbol).map_err(|_| Error::<T>::BadMetadata)?;
+
+            AssetInfo::<T>::insert(asset_id, AssetMetadata { name, symbol, decimals, min_balance });
+
+            Self::deposit_event(Event::AssetCreated { asset_id });
+
+            Ok(())
+        }
+
         /// An unsafe mint that can be called by anyone. Not a great idea.
         pub fn mint_unsafe(
             origin: T::RuntimeOrigin,
+            asset_id: T::AssetId,
             dest: T::AccountId,
             amount: Balance,
         ) -> DispatchResult {
-            // ensure that this is a signed account, but we don't really check `_who`.
-            let _who = ensure_signed(origin)?;
+            // ensure that this is a signed account, but we don't really check who it is.
+            let who = ensure_signed(origin)?;
+
+            Self::ensure_kyc_approved(&who)?;
+            Self::ensure_kyc_approved(&dest)?;
 
-            ensure!(amount >= T::min_amount(), Error::<T>::BelowMinAmount);
+            ensure!(amount >= Self::min_balance(asset_id)?, Error::<T>::BelowMinAmount);
 
-            // update the `BalanceOf` map. Notice how all `<T: Config>` remains as `<T>`.
-            BalanceOf::<T>::mutate(dest.clone(), |b| *b = Some(b.unwrap_or(0) + amount));
+            Self::credit(asset_id, &dest, amount)?;
             // update total issuance.
-            TotalIssuance::<T>::mutate(|t| *t = Some(t.unwrap_or(0) + amount));
-        
-			Self::deposit_event(Event::Mint { to: dest, amount: amount });
+            TotalIssuance::<T>::mutate(asset_id, |t| *t = Some(t.unwrap_or(0) + amount));
+
+			Self::deposit_event(Event::Mint { asset_id, to: dest, amount: amount });
 
             Ok(())
         }
 
-        /// Transfer `amount` from `origin` to `dest`.
+        /// Transfer `amount` of `asset_id` from `origin` to `dest`. Only the sender's free
+        /// (non-held) balance may be moved.
         pub fn transfer(
             origin: T::RuntimeOrigin,
+            asset_id: T::AssetId,
             dest: T::AccountId,
             amount: Balance,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
 
-            // ensure sender has enough balance, and if so, calculate what is left after `amount`.
-            let sender_balance = BalanceOf::<T>::get(&sender).ok_or(Error::<T>::NonExistentAccount)?;
-           	let remainder = sender_balance.checked_sub(amount).ok_or(Error::<T>::InsufficientBalance)?;
+            Self::do_transfer(asset_id, sender, dest, amount)
+        }
+
+        /// Approve `spender` to move up to `amount` of the caller's `asset_id` via
+        /// `transfer_from`. A later `approve` call overwrites the previous allowance.
+        pub fn approve(
+            origin: T::RuntimeOrigin,
+            asset_id: T::AssetId,
+            spender: T::AccountId,
+            amount: Balance,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+
+            Allowances::<T>::insert((asset_id, owner.clone(), spender.clone()), amount);
+
+            Self::deposit_event(Event::Approval { asset_id, owner, spender, amount });
+
+            Ok(())
+        }
+
+        /// Move `amount` of `asset_id` from `owner` to `dest` on the caller's behalf, consuming
+        /// that much of the allowance `owner` granted the caller via `approve`.
+        pub fn transfer_from(
+            origin: T::RuntimeOrigin,
+            asset_id: T::AssetId,
+            owner: T::AccountId,
+            dest: T::AccountId,
+            amount: Balance,
+        ) -> DispatchResult {
+            let spender = ensure_signed(origin)?;
+
+            let allowance =
+                Allowances::<T>::get((asset_id, owner.clone(), spender.clone())).unwrap_or(0);
+            let remaining_allowance =
+                allowance.checked_sub(amount).ok_or(Error::<T>::InsufficientAllowance)?;
+
+            // only spend down the allowance once the transfer itself has actually succeeded;
+            // this isn't `#[transactional]`, so a failed `do_transfer` (e.g. `InsufficientBalance`
+            // or `NotKycApproved`) must not permanently burn part of the owner's allowance.
+            Self::do_transfer(asset_id, owner.clone(), dest, amount)?;
+
+            Allowances::<T>::insert((asset_id, owner.clone(), spender.clone()), remaining_allowance);
+
+            Self::deposit_event(Event::Approval {
+                asset_id,
+                owner,
+                spender,
+                amount: remaining_allowance,
+            });
+
+            Ok(())
+        }
+
+        /// Burn `amount` of `asset_id` from the caller's own free balance, permanently removing
+        /// it from `TotalIssuance`.
+        pub fn burn(origin: T::RuntimeOrigin, asset_id: T::AssetId, amount: Balance) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let balance =
+                BalanceOf::<T>::get(asset_id, &who).ok_or(Error::<T>::NonExistentAccount)?;
+            ensure!(Self::free_balance_of(asset_id, &who) >= amount, Error::<T>::InsufficientBalance);
+            let remainder = balance - amount;
+
+            Self::debit(asset_id, &who, remainder);
+            // total issuance can never underflow past what was minted, but saturate rather
+            // than panic if storage ever ends up inconsistent.
+            TotalIssuance::<T>::mutate(asset_id, |t| *t = Some(t.unwrap_or(0).saturating_sub(amount)));
+
+            Self::deposit_event(Event::Burn { asset_id, from: who, amount });
+
+            Ok(())
+        }
+
+        /// Set `who`'s KYC status. Only callable by `Config::KycAdmin`.
+        pub fn set_kyc(origin: T::RuntimeOrigin, who: T::AccountId, status: KycStatus) -> DispatchResult {
+            T::KycAdmin::ensure_origin(origin)?;
+
+            Kyc::<T>::insert(&who, status);
+
+            Self::deposit_event(Event::KycUpdated { who, status });
+
+            Ok(())
+        }
+
+        /// Move `amount` of the caller's free balance of `asset_id` into a hold for `reason`.
+        pub fn hold(
+            origin: T::RuntimeOrigin,
+            asset_id: T::AssetId,
+            reason: HoldReason,
+            amount: Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(Self::free_balance_of(asset_id, &who) >= amount, Error::<T>::InsufficientBalance);
+
+            Holds::<T>::try_mutate((asset_id, who.clone()), |maybe_holds| -> DispatchResult {
+                let mut holds = maybe_holds.clone().unwrap_or_default();
+                match holds.iter_mut().find(|(r, _)| *r == reason) {
+                    Some(entry) => entry.1 += amount,
+                    None => holds.try_push((reason, amount)).map_err(|_| Error::<T>::TooManyHolds)?,
+                }
+                *maybe_holds = Some(holds);
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::Held { asset_id, who, reason, amount });
+
+            Ok(())
+        }
+
+        /// Release `amount` previously held for `reason`, returning it to the free balance.
+        pub fn release(
+            origin: T::RuntimeOrigin,
+            asset_id: T::AssetId,
+            reason: HoldReason,
+            amount: Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Holds::<T>::try_mutate((asset_id, who.clone()), |maybe_holds| -> DispatchResult {
+                let mut holds = maybe_holds.take().ok_or(Error::<T>::InsufficientBalance)?;
+                let entry = holds
+                    .iter_mut()
+                    .find(|(r, _)| *r == reason)
+                    .ok_or(Error::<T>::InsufficientBalance)?;
+                entry.1 = entry.1.checked_sub(amount).ok_or(Error::<T>::InsufficientBalance)?;
+                holds.retain(|(_, held)| *held > 0);
+
+                if !holds.is_empty() {
+                    *maybe_holds = Some(holds);
+                }
+
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::Released { asset_id, who, reason, amount });
+
+            Ok(())
+        }
+
+        /// Mint `Config::faucet_amount()` of `asset_id` to the caller, at most once per
+        /// `Config::faucet_cooldown()` blocks. A safer stand-in for `mint_unsafe` on testnets.
+        pub fn faucet(origin: T::RuntimeOrigin, asset_id: T::AssetId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::ensure_kyc_approved(&who)?;
+
+            let now = polkadot_sdk::frame_system::Pallet::<T>::block_number();
+            if let Some(last) = LastFaucet::<T>::get((asset_id, who.clone())) {
+                ensure!(now.saturating_sub(last) >= T::faucet_cooldown(), Error::<T>::FaucetCooldown);
+            }
+
+            let amount = T::faucet_amount();
+            Self::credit(asset_id, &who, amount)?;
+            TotalIssuance::<T>::mutate(asset_id, |t| *t = Some(t.unwrap_or(0) + amount));
+            LastFaucet::<T>::insert((asset_id, who.clone()), now);
+
+            Self::deposit_event(Event::Mint { asset_id, to: who, amount });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The free balance of `account` in `asset`, or `0` if it has never held any.
+        ///
+        /// Backs the `free_balance` method of `CurrencyApi` in `pallet-currency-runtime-api`.
+        pub fn free_balance(asset: T::AssetId, account: T::AccountId) -> Balance {
+            Self::free_balance_of(asset, &account)
+        }
+
+        /// The total issuance of `asset`, or `0` if it doesn't exist or nothing was minted.
+        ///
+        /// Backs the `total_issuance` method of `CurrencyApi` in `pallet-currency-runtime-api`.
+        pub fn total_issuance(asset: T::AssetId) -> Balance {
+            TotalIssuance::<T>::get(asset).unwrap_or(0)
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The minimum amount `mint_unsafe` accepts for `asset_id`, falling back to
+        /// `Config::min_amount()` when the asset didn't specify one.
+        fn min_balance(asset_id: T::AssetId) -> Result<Balance, DispatchError> {
+            let info = AssetInfo::<T>::get(asset_id).ok_or(Error::<T>::UnknownAsset)?;
+            Ok(info.min_balance.unwrap_or_else(T::min_amount))
+        }
+
+        /// When `Config::kyc_enforced()` is on, reject `who` unless it is `Approved`.
+        fn ensure_kyc_approved(who: &T::AccountId) -> DispatchResult {
+            if !T::kyc_enforced() {
+                return Ok(());
+            }
+
+            ensure!(Kyc::<T>::get(who) == Some(KycStatus::Approved), Error::<T>::NotKycApproved);
+
+            Ok(())
+        }
+
+        /// `who`'s balance of `asset_id` minus everything currently held against it.
+        fn free_balance_of(asset_id: T::AssetId, who: &T::AccountId) -> Balance {
+            let total = BalanceOf::<T>::get(asset_id, who).unwrap_or(0);
+            let held = Holds::<T>::get((asset_id, who.clone()))
+                .map(|holds| holds.iter().fold(0u128, |acc, (_, amount)| acc.saturating_add(*amount)))
+                .unwrap_or(0);
+
+            total.saturating_sub(held)
+        }
+
+        /// Credit `who` with `amount` of `asset_id`. Rejects creating a brand new account with
+        /// less than `Config::existential_deposit()`.
+        fn credit(asset_id: T::AssetId, who: &T::AccountId, amount: Balance) -> DispatchResult {
+            let existed = BalanceOf::<T>::contains_key(asset_id, who);
+            let new_balance = BalanceOf::<T>::get(asset_id, who).unwrap_or(0) + amount;
+
+            if !existed {
+                ensure!(new_balance >= T::existential_deposit(), Error::<T>::ExistentialDeposit);
+            }
+
+            BalanceOf::<T>::insert(asset_id, who, new_balance);
+
+            Ok(())
+        }
+
+        /// Set `who`'s balance of `asset_id` to `remaining` after a debit. If that would leave
+        /// a non-zero balance below `Config::existential_deposit()`, the account is reaped and
+        /// the dust is routed to the treasury instead - but only while `who` has no open
+        /// `Holds`. An account with an active hold keeps its (sub-ED) balance rather than being
+        /// reaped, since reaping would otherwise force-release the hold and strand the value it
+        /// was meant to lock; `release()` is the only sanctioned way to free held funds.
+        fn debit(asset_id: T::AssetId, who: &T::AccountId, remaining: Balance) {
+            if remaining == 0 {
+                BalanceOf::<T>::remove(asset_id, who);
+            } else if remaining < T::existential_deposit()
+                && !Holds::<T>::get((asset_id, who.clone())).is_some_and(|h| !h.is_empty())
+            {
+                BalanceOf::<T>::remove(asset_id, who);
+
+                let treasury = T::treasury_account();
+                BalanceOf::<T>::mutate(asset_id, treasury, |b| *b = Some(b.unwrap_or(0) + remaining));
+                TreasuryBalance::<T>::mutate(asset_id, |t| *t = Some(t.unwrap_or(0) + remaining));
+            } else {
+                BalanceOf::<T>::insert(asset_id, who, remaining);
+            }
+        }
+
+        /// The fee `Config::fee_rate()` takes out of `amount` (rounded down), and what's left
+        /// for the destination to actually receive.
+        fn calculate_fee(amount: Balance) -> (Balance, Balance) {
+            let fee = T::fee_rate().mul_floor(amount);
+            (fee, amount - fee)
+        }
+
+        /// Commit `fee` to the treasury account. Callers must only invoke this after the
+        /// destination has already been credited, so a transfer that fails partway through
+        /// can't leave the fee sitting in the treasury with no compensating debit from the
+        /// sender.
+        fn commit_fee(asset_id: T::AssetId, fee: Balance) {
+            if fee == 0 {
+                return;
+            }
+
+            let treasury = T::treasury_account();
+            BalanceOf::<T>::mutate(asset_id, treasury, |b| *b = Some(b.unwrap_or(0) + fee));
+            TreasuryBalance::<T>::mutate(asset_id, |t| *t = Some(t.unwrap_or(0) + fee));
+        }
+
+        /// Shared body of `transfer` and `transfer_from`: move `amount` of `asset_id` from
+        /// `sender` to `dest` out of the sender's free balance, net of the treasury fee.
+        fn do_transfer(
+            asset_id: T::AssetId,
+            sender: T::AccountId,
+            dest: T::AccountId,
+            amount: Balance,
+        ) -> DispatchResult {
+            Self::ensure_kyc_approved(&sender)?;
+            Self::ensure_kyc_approved(&dest)?;
+
+            // ensure sender has enough *free* balance, and if so, calculate what is left after
+            // `amount`.
+            let sender_balance =
+                BalanceOf::<T>::get(asset_id, &sender).ok_or(Error::<T>::NonExistentAccount)?;
+            ensure!(Self::free_balance_of(asset_id, &sender) >= amount, Error::<T>::InsufficientBalance);
+            let remainder = sender_balance - amount;
 
-            // update sender and dest `BalanceOf`.
-            BalanceOf::<T>::mutate(dest.clone(), |b| *b = Some(b.unwrap_or(0) + amount));
-            BalanceOf::<T>::insert(&sender, remainder);
+            // the fee stays behind in the treasury, only the remainder reaches `dest`. Credit
+            // `dest` first: it can still fail (e.g. `ExistentialDeposit` on a new account), and
+            // this isn't `#[transactional]`, so the fee must not be committed until we know the
+            // rest of the transfer will actually go through.
+            let (fee, net_amount) = Self::calculate_fee(amount);
 
-            Self::deposit_event(Event::Transfer { from: sender, to: dest, amount: amount });
+            Self::credit(asset_id, &dest, net_amount)?;
+            Self::commit_fee(asset_id, fee);
+            Self::debit(asset_id, &sender, remainder);
+
+            Self::deposit_event(Event::Transfer {
+                asset_id,
+                from: sender,
+                to: dest,
+                amount: net_amount,
+            });
 
             Ok(())
         }
@@ -113,64 +529,174 @@ mod test {
 		type AccountId = u64;
 	}
 
+    pub const TREASURY: <Runtime as frame_system::Config>::AccountId = 999;
+    pub const ASSET: <Runtime as pallet_currency::Config>::AssetId = 1;
+    pub const OTHER_ASSET: <Runtime as pallet_currency::Config>::AssetId = 2;
+
+    // Whether the gated KYC path is enforced, toggled per-test. Defaults to off so that the
+    // pre-existing tests don't have to onboard every account they use.
+    thread_local! {
+        static KYC_ENFORCED: core::cell::RefCell<bool> = const { core::cell::RefCell::new(false) };
+        static EXISTENTIAL_DEPOSIT: core::cell::RefCell<pallet_currency::Balance> =
+            const { core::cell::RefCell::new(0) };
+        // Defaults to the 1% used by the pre-existing fee tests.
+        static FEE_RATE: core::cell::RefCell<Permill> = core::cell::RefCell::new(Permill::from_percent(1));
+    }
+
+    fn set_kyc_enforced(enforced: bool) {
+        KYC_ENFORCED.with(|v| *v.borrow_mut() = enforced);
+    }
+
+    fn set_existential_deposit(ed: pallet_currency::Balance) {
+        EXISTENTIAL_DEPOSIT.with(|v| *v.borrow_mut() = ed);
+    }
+
+    fn set_fee_rate(rate: Permill) {
+        FEE_RATE.with(|v| *v.borrow_mut() = rate);
+    }
+
     impl pallet_currency::Config for Runtime {
         type RuntimeEvent = RuntimeEvent;
+        type AssetId = u32;
+        type KycAdmin = frame_system::EnsureRoot<Self::AccountId>;
+        type MaxHolds = ConstU32<2>;
 
         fn min_amount() -> pallet_currency::Balance {
             1
         }
+
+        fn treasury_account() -> Self::AccountId {
+            TREASURY
+        }
+
+        fn fee_rate() -> Permill {
+            FEE_RATE.with(|v| *v.borrow())
+        }
+
+        fn kyc_enforced() -> bool {
+            KYC_ENFORCED.with(|v| *v.borrow())
+        }
+
+        fn existential_deposit() -> pallet_currency::Balance {
+            EXISTENTIAL_DEPOSIT.with(|v| *v.borrow())
+        }
+
+        fn faucet_cooldown() -> BlockNumberFor<Runtime> {
+            10
+        }
+
+        fn faucet_amount() -> pallet_currency::Balance {
+            50
+        }
+    }
+
+    /// Create `asset` with no bespoke `min_balance`, so it falls back to `Config::min_amount()`.
+    fn create_asset(asset: <Runtime as pallet_currency::Config>::AssetId) {
+        assert_ok!(pallet_currency::Pallet::<Runtime>::create_asset(
+            RuntimeOrigin::signed(1),
+            asset,
+            b"Asset".to_vec(),
+            b"AST".to_vec(),
+            10,
+            None,
+        ));
+    }
+
+    #[test]
+    fn should_create_asset() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+
+            assert!(pallet_currency::AssetInfo::<Runtime>::get(ASSET).is_some());
+            System::assert_has_event(pallet_currency::Event::AssetCreated { asset_id: ASSET }.into());
+        });
+    }
+
+    #[test]
+    fn should_not_create_asset_twice() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+
+            assert_noop!(
+                pallet_currency::Pallet::<Runtime>::create_asset(
+                    RuntimeOrigin::signed(1),
+                    ASSET,
+                    b"Asset".to_vec(),
+                    b"AST".to_vec(),
+                    10,
+                    None,
+                ),
+                pallet_currency::Error::<Runtime>::AssetAlreadyExists
+            );
+        });
     }
 
     #[test]
     fn should_mint_unsafe() {
         TestState::new_empty().execute_with(|| {
             System::set_block_number(1);
+            create_asset(ASSET);
 
-            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(1), None);
-            assert_eq!(pallet_currency::TotalIssuance::<Runtime>::get(), None);
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, 1), None);
+            assert_eq!(pallet_currency::TotalIssuance::<Runtime>::get(ASSET), None);
 
             const DEST: <Runtime as frame_system::Config>::AccountId = 1;
             const AMOUNT: pallet_currency::Balance = 100;
-            
+
             assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
                 RuntimeOrigin::signed(1),
+                ASSET,
                 DEST,
                 AMOUNT
             ));
 
             // re-check the above
-            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(1), Some(100));
-            assert_eq!(pallet_currency::TotalIssuance::<Runtime>::get(), Some(100));
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, 1), Some(100));
+            assert_eq!(pallet_currency::TotalIssuance::<Runtime>::get(ASSET), Some(100));
 
-            let events = System::events();
-            assert_eq!(events.len(), 1);
-            System::assert_has_event(pallet_currency::Event::Mint { to: DEST, amount: AMOUNT }.into());
+            System::assert_has_event(
+                pallet_currency::Event::Mint { asset_id: ASSET, to: DEST, amount: AMOUNT }.into(),
+            );
         });
     }
 
     #[test]
     fn should_not_mint_unsafe_below_min_amount()  {
         TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+
             assert_noop!(
-                pallet_currency::Pallet::<Runtime>::mint_unsafe(RuntimeOrigin::signed(1), 2, 0),
+                pallet_currency::Pallet::<Runtime>::mint_unsafe(RuntimeOrigin::signed(1), ASSET, 2, 0),
                 pallet_currency::Error::<Runtime>::BelowMinAmount
             );
         });
     }
 
+    #[test]
+    fn should_not_mint_unsafe_unknown_asset()  {
+        TestState::new_empty().execute_with(|| {
+            assert_noop!(
+                pallet_currency::Pallet::<Runtime>::mint_unsafe(RuntimeOrigin::signed(1), ASSET, 2, 10),
+                pallet_currency::Error::<Runtime>::UnknownAsset
+            );
+        });
+    }
+
 
     #[test]
     fn should_transfer()  {
         TestState::new_empty().execute_with(|| {
             System::set_block_number(1);
+            create_asset(ASSET);
             assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
                 RuntimeOrigin::signed(1),
+                ASSET,
                 1,
                 100
             ));
 
-            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(1), Some(100));
-            assert_eq!(pallet_currency::TotalIssuance::<Runtime>::get(), Some(100));
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, 1), Some(100));
+            assert_eq!(pallet_currency::TotalIssuance::<Runtime>::get(ASSET), Some(100));
 
             const FROM: <Runtime as frame_system::Config>::AccountId  = 1;
             const TO: <Runtime as frame_system::Config>::AccountId  = 2;
@@ -178,12 +704,16 @@ mod test {
 
             assert_ok!(pallet_currency::Pallet::<Runtime>::transfer(
                 RuntimeOrigin::signed(FROM),
+                ASSET,
                 TO,
                 AMOUNT
             ));
-            System::assert_has_event(pallet_currency::Event::Transfer { from: FROM, to: TO, amount: AMOUNT }.into());
+            System::assert_has_event(
+                pallet_currency::Event::Transfer { asset_id: ASSET, from: FROM, to: TO, amount: AMOUNT }
+                    .into(),
+            );
 
-            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(2), Some(50));
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, 2), Some(50));
 
         });
     }
@@ -191,17 +721,19 @@ mod test {
     #[test]
     fn should_not_transfer_insufficient_balance()  {
         TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
             assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
                 RuntimeOrigin::signed(1),
+                ASSET,
                 1,
                 100
             ));
 
-            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(1), Some(100));
-            assert_eq!(pallet_currency::TotalIssuance::<Runtime>::get(), Some(100));
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, 1), Some(100));
+            assert_eq!(pallet_currency::TotalIssuance::<Runtime>::get(ASSET), Some(100));
 
             assert_noop!(
-                pallet_currency::Pallet::<Runtime>::transfer(RuntimeOrigin::signed(1), 2, 101),
+                pallet_currency::Pallet::<Runtime>::transfer(RuntimeOrigin::signed(1), ASSET, 2, 101),
                 pallet_currency::Error::<Runtime>::InsufficientBalance
             );
         });
@@ -210,12 +742,710 @@ mod test {
     #[test]
     fn should_not_transfer_non_existent_account()  {
         TestState::new_empty().execute_with(|| {
-            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(6), None);
+            create_asset(ASSET);
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, 6), None);
+
+            assert_noop!(
+                pallet_currency::Pallet::<Runtime>::transfer(RuntimeOrigin::signed(6), ASSET, 2, 101),
+                pallet_currency::Error::<Runtime>::NonExistentAccount
+            );
+        });
+    }
+
+    #[test]
+    fn should_burn() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+            assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                1,
+                100
+            ));
+
+            assert_ok!(pallet_currency::Pallet::<Runtime>::burn(RuntimeOrigin::signed(1), ASSET, 40));
+
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, 1), Some(60));
+            assert_eq!(pallet_currency::TotalIssuance::<Runtime>::get(ASSET), Some(60));
+            System::assert_has_event(
+                pallet_currency::Event::Burn { asset_id: ASSET, from: 1, amount: 40 }.into(),
+            );
+        });
+    }
+
+    #[test]
+    fn should_not_burn_below_zero() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+            assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                1,
+                100
+            ));
+
+            assert_noop!(
+                pallet_currency::Pallet::<Runtime>::burn(RuntimeOrigin::signed(1), ASSET, 101),
+                pallet_currency::Error::<Runtime>::InsufficientBalance
+            );
+        });
+    }
 
+    #[test]
+    fn should_not_burn_non_existent_account() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
             assert_noop!(
-                pallet_currency::Pallet::<Runtime>::transfer(RuntimeOrigin::signed(6), 2, 101),
+                pallet_currency::Pallet::<Runtime>::burn(RuntimeOrigin::signed(1), ASSET, 1),
                 pallet_currency::Error::<Runtime>::NonExistentAccount
             );
         });
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn fee_rounds_down_at_small_amounts() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+            assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                1,
+                350
+            ));
+
+            // 1% of 50 is 0.5, which truncates to 0: no fee is skimmed and the treasury
+            // stays empty.
+            assert_ok!(pallet_currency::Pallet::<Runtime>::transfer(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                2,
+                50
+            ));
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, 2), Some(50));
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, TREASURY), None);
+            assert_eq!(pallet_currency::TreasuryBalance::<Runtime>::get(ASSET), None);
+
+            // 1% of 300 is 3, which is large enough to actually be routed to the treasury.
+            assert_ok!(pallet_currency::Pallet::<Runtime>::transfer(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                2,
+                300
+            ));
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, 2), Some(50 + 297));
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, TREASURY), Some(3));
+            assert_eq!(pallet_currency::TreasuryBalance::<Runtime>::get(ASSET), Some(3));
+        });
+    }
+
+    #[test]
+    fn total_issuance_invariant_after_burn() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+            assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                1,
+                100
+            ));
+            assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                2,
+                50
+            ));
+
+            assert_ok!(pallet_currency::Pallet::<Runtime>::burn(RuntimeOrigin::signed(1), ASSET, 30));
+
+            // total issuance must always equal the sum of all account balances.
+            let sum = pallet_currency::BalanceOf::<Runtime>::get(ASSET, 1).unwrap_or(0)
+                + pallet_currency::BalanceOf::<Runtime>::get(ASSET, 2).unwrap_or(0);
+            assert_eq!(pallet_currency::TotalIssuance::<Runtime>::get(ASSET), Some(sum));
+            assert_eq!(pallet_currency::TotalIssuance::<Runtime>::get(ASSET), Some(120));
+        });
+    }
+
+    #[test]
+    fn assets_are_isolated_from_each_other() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+            create_asset(OTHER_ASSET);
+
+            assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                1,
+                100
+            ));
+            assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
+                RuntimeOrigin::signed(1),
+                OTHER_ASSET,
+                1,
+                7
+            ));
+
+            // minting `ASSET` must not be visible under `OTHER_ASSET`, and vice versa.
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, 1), Some(100));
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(OTHER_ASSET, 1), Some(7));
+            assert_eq!(pallet_currency::TotalIssuance::<Runtime>::get(ASSET), Some(100));
+            assert_eq!(pallet_currency::TotalIssuance::<Runtime>::get(OTHER_ASSET), Some(7));
+
+            assert_ok!(pallet_currency::Pallet::<Runtime>::burn(RuntimeOrigin::signed(1), ASSET, 100));
+
+            // a fully-drained account is reaped rather than left behind as an explicit zero.
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, 1), None);
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(OTHER_ASSET, 1), Some(7));
+        });
+    }
+
+    #[test]
+    fn should_set_kyc() {
+        TestState::new_empty().execute_with(|| {
+            assert_eq!(pallet_currency::Kyc::<Runtime>::get(1), None);
+
+            assert_ok!(pallet_currency::Pallet::<Runtime>::set_kyc(
+                RuntimeOrigin::root(),
+                1,
+                pallet_currency::KycStatus::Approved,
+            ));
+
+            assert_eq!(pallet_currency::Kyc::<Runtime>::get(1), Some(pallet_currency::KycStatus::Approved));
+            System::assert_has_event(
+                pallet_currency::Event::KycUpdated { who: 1, status: pallet_currency::KycStatus::Approved }
+                    .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn should_not_set_kyc_from_non_admin_origin() {
+        TestState::new_empty().execute_with(|| {
+            assert_noop!(
+                pallet_currency::Pallet::<Runtime>::set_kyc(
+                    RuntimeOrigin::signed(1),
+                    1,
+                    pallet_currency::KycStatus::Approved,
+                ),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+
+    #[test]
+    fn gated_mint_and_transfer_reject_unapproved_accounts() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+            set_kyc_enforced(true);
+
+            assert_noop!(
+                pallet_currency::Pallet::<Runtime>::mint_unsafe(RuntimeOrigin::signed(1), ASSET, 1, 100),
+                pallet_currency::Error::<Runtime>::NotKycApproved
+            );
+
+            assert_ok!(pallet_currency::Pallet::<Runtime>::set_kyc(
+                RuntimeOrigin::root(),
+                1,
+                pallet_currency::KycStatus::Approved,
+            ));
+            assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                1,
+                100
+            ));
+
+            // `1` is approved but `2` isn't, so a transfer between them is still rejected.
+            assert_noop!(
+                pallet_currency::Pallet::<Runtime>::transfer(RuntimeOrigin::signed(1), ASSET, 2, 10),
+                pallet_currency::Error::<Runtime>::NotKycApproved
+            );
+
+            assert_ok!(pallet_currency::Pallet::<Runtime>::set_kyc(
+                RuntimeOrigin::root(),
+                2,
+                pallet_currency::KycStatus::Approved,
+            ));
+            assert_ok!(pallet_currency::Pallet::<Runtime>::transfer(RuntimeOrigin::signed(1), ASSET, 2, 10));
+        });
+    }
+
+    // These exercise the exact accessors the runtime API in `pallet-currency-runtime-api` and
+    // the RPC server in `pallet-currency-rpc` call through to.
+    #[test]
+    fn runtime_api_free_balance_and_total_issuance() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+
+            assert_eq!(pallet_currency::Pallet::<Runtime>::free_balance(ASSET, 1), 0);
+            assert_eq!(pallet_currency::Pallet::<Runtime>::total_issuance(ASSET), 0);
+
+            assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                1,
+                100
+            ));
+
+            assert_eq!(pallet_currency::Pallet::<Runtime>::free_balance(ASSET, 1), 100);
+            assert_eq!(pallet_currency::Pallet::<Runtime>::total_issuance(ASSET), 100);
+        });
+    }
+
+    #[test]
+    fn runtime_api_defaults_to_zero_for_unknown_asset() {
+        TestState::new_empty().execute_with(|| {
+            assert_eq!(pallet_currency::Pallet::<Runtime>::free_balance(ASSET, 1), 0);
+            assert_eq!(pallet_currency::Pallet::<Runtime>::total_issuance(ASSET), 0);
+        });
+    }
+
+    #[test]
+    fn should_hold_and_release() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+            assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                1,
+                100
+            ));
+
+            assert_ok!(pallet_currency::Pallet::<Runtime>::hold(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                pallet_currency::HoldReason::Staking,
+                40,
+            ));
+            System::assert_has_event(
+                pallet_currency::Event::Held {
+                    asset_id: ASSET,
+                    who: 1,
+                    reason: pallet_currency::HoldReason::Staking,
+                    amount: 40,
+                }
+                .into(),
+            );
+
+            // the total balance is untouched, but the free balance shrinks.
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, 1), Some(100));
+            assert_eq!(pallet_currency::Pallet::<Runtime>::free_balance(ASSET, 1), 60);
+
+            assert_ok!(pallet_currency::Pallet::<Runtime>::release(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                pallet_currency::HoldReason::Staking,
+                40,
+            ));
+            System::assert_has_event(
+                pallet_currency::Event::Released {
+                    asset_id: ASSET,
+                    who: 1,
+                    reason: pallet_currency::HoldReason::Staking,
+                    amount: 40,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn held_balance_is_protected_from_transfer() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+            assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                1,
+                100
+            ));
+            assert_ok!(pallet_currency::Pallet::<Runtime>::hold(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                pallet_currency::HoldReason::Staking,
+                60,
+            ));
+
+            // only 40 is free, so moving more than that must fail.
+            assert_noop!(
+                pallet_currency::Pallet::<Runtime>::transfer(RuntimeOrigin::signed(1), ASSET, 2, 41),
+                pallet_currency::Error::<Runtime>::InsufficientBalance
+            );
+
+            assert_ok!(pallet_currency::Pallet::<Runtime>::transfer(RuntimeOrigin::signed(1), ASSET, 2, 40));
+        });
+    }
+
+    #[test]
+    fn should_not_release_more_than_held() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+            assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                1,
+                100
+            ));
+            assert_ok!(pallet_currency::Pallet::<Runtime>::hold(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                pallet_currency::HoldReason::Staking,
+                10,
+            ));
+
+            assert_noop!(
+                pallet_currency::Pallet::<Runtime>::release(
+                    RuntimeOrigin::signed(1),
+                    ASSET,
+                    pallet_currency::HoldReason::Staking,
+                    11,
+                ),
+                pallet_currency::Error::<Runtime>::InsufficientBalance
+            );
+        });
+    }
+
+    #[test]
+    fn should_not_hold_past_max_holds() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+            assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                1,
+                100
+            ));
+
+            // `MaxHolds` is 2 in this mock.
+            assert_ok!(pallet_currency::Pallet::<Runtime>::hold(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                pallet_currency::HoldReason::Staking,
+                10,
+            ));
+            assert_ok!(pallet_currency::Pallet::<Runtime>::hold(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                pallet_currency::HoldReason::Governance,
+                10,
+            ));
+
+            assert_noop!(
+                pallet_currency::Pallet::<Runtime>::hold(
+                    RuntimeOrigin::signed(1),
+                    ASSET,
+                    pallet_currency::HoldReason::Other,
+                    10,
+                ),
+                pallet_currency::Error::<Runtime>::TooManyHolds
+            );
+        });
+    }
+
+    #[test]
+    fn transfer_reaps_dust_into_treasury() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+            set_existential_deposit(10);
+
+            assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                1,
+                100
+            ));
+
+            // leaves the sender with 5, which is below the existential deposit of 10.
+            assert_ok!(pallet_currency::Pallet::<Runtime>::transfer(RuntimeOrigin::signed(1), ASSET, 2, 95));
+
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, 1), None);
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, TREASURY), Some(5));
+
+            // the total issuance is unaffected: the dust just moved to the treasury.
+            assert_eq!(pallet_currency::TotalIssuance::<Runtime>::get(ASSET), Some(100));
+        });
+    }
+
+    #[test]
+    fn mint_rejects_new_account_below_existential_deposit() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+            set_existential_deposit(10);
+
+            assert_noop!(
+                pallet_currency::Pallet::<Runtime>::mint_unsafe(RuntimeOrigin::signed(1), ASSET, 1, 5),
+                pallet_currency::Error::<Runtime>::ExistentialDeposit
+            );
+
+            // topping up an already-existing account past the deposit is unaffected.
+            assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                1,
+                10
+            ));
+            assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                1,
+                1
+            ));
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, 1), Some(11));
+        });
+    }
+
+    #[test]
+    fn transfer_from_spends_down_the_allowance() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+
+            assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                1,
+                100
+            ));
+            assert_ok!(pallet_currency::Pallet::<Runtime>::approve(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                2,
+                30
+            ));
+
+            assert_ok!(pallet_currency::Pallet::<Runtime>::transfer_from(
+                RuntimeOrigin::signed(2),
+                ASSET,
+                1,
+                3,
+                20
+            ));
+
+            assert_eq!(
+                pallet_currency::Allowances::<Runtime>::get((ASSET, 1, 2)),
+                Some(10)
+            );
+            System::assert_has_event(
+                pallet_currency::Event::Approval { asset_id: ASSET, owner: 1, spender: 2, amount: 10 }
+                    .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn transfer_from_rejects_amount_above_the_allowance() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+
+            assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                1,
+                100
+            ));
+            assert_ok!(pallet_currency::Pallet::<Runtime>::approve(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                2,
+                30
+            ));
+
+            assert_noop!(
+                pallet_currency::Pallet::<Runtime>::transfer_from(
+                    RuntimeOrigin::signed(2),
+                    ASSET,
+                    1,
+                    3,
+                    31
+                ),
+                pallet_currency::Error::<Runtime>::InsufficientAllowance
+            );
+
+            // the allowance is untouched by the rejected attempt.
+            assert_eq!(
+                pallet_currency::Allowances::<Runtime>::get((ASSET, 1, 2)),
+                Some(30)
+            );
+        });
+    }
+
+    #[test]
+    fn transfer_from_cannot_be_replayed_once_exhausted() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+
+            assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                1,
+                100
+            ));
+            assert_ok!(pallet_currency::Pallet::<Runtime>::approve(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                2,
+                30
+            ));
+
+            assert_ok!(pallet_currency::Pallet::<Runtime>::transfer_from(
+                RuntimeOrigin::signed(2),
+                ASSET,
+                1,
+                3,
+                30
+            ));
+
+            // the allowance is now fully spent; trying to reuse it fails.
+            assert_noop!(
+                pallet_currency::Pallet::<Runtime>::transfer_from(
+                    RuntimeOrigin::signed(2),
+                    ASSET,
+                    1,
+                    3,
+                    1
+                ),
+                pallet_currency::Error::<Runtime>::InsufficientAllowance
+            );
+        });
+    }
+
+    #[test]
+    fn faucet_enforces_the_cooldown_across_blocks() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+
+            assert_ok!(pallet_currency::Pallet::<Runtime>::faucet(RuntimeOrigin::signed(1), ASSET));
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, 1), Some(50));
+
+            // still within the 10-block cooldown.
+            System::set_block_number(5);
+            assert_noop!(
+                pallet_currency::Pallet::<Runtime>::faucet(RuntimeOrigin::signed(1), ASSET),
+                pallet_currency::Error::<Runtime>::FaucetCooldown
+            );
+
+            // the cooldown has now elapsed.
+            System::set_block_number(10);
+            assert_ok!(pallet_currency::Pallet::<Runtime>::faucet(RuntimeOrigin::signed(1), ASSET));
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, 1), Some(100));
+        });
+    }
+
+    #[test]
+    fn transfer_does_not_reap_an_account_with_open_holds() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+            set_existential_deposit(50);
+
+            assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                1,
+                100
+            ));
+            // free balance is 55, so this transfer is within bounds...
+            assert_ok!(pallet_currency::Pallet::<Runtime>::hold(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                pallet_currency::HoldReason::Staking,
+                45,
+            ));
+
+            // ...and it would leave a total balance of 45, below the existential deposit of 50.
+            // Reaping must be skipped rather than force-releasing the still-open 45-unit hold:
+            // `release()` is the only sanctioned way to free held funds.
+            assert_ok!(pallet_currency::Pallet::<Runtime>::transfer(RuntimeOrigin::signed(1), ASSET, 2, 55));
+
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, 1), Some(45));
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, TREASURY), None);
+            let holds = pallet_currency::Holds::<Runtime>::get((ASSET, 1)).expect("hold still open");
+            assert_eq!(holds.to_vec(), vec![(pallet_currency::HoldReason::Staking, 45)]);
+
+            // once the hold is released, the dust is no longer protected and a further debit
+            // reaps it as usual.
+            assert_ok!(pallet_currency::Pallet::<Runtime>::release(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                pallet_currency::HoldReason::Staking,
+                45,
+            ));
+            assert_ok!(pallet_currency::Pallet::<Runtime>::transfer(RuntimeOrigin::signed(1), ASSET, 2, 10));
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, 1), None);
+            assert_eq!(pallet_currency::BalanceOf::<Runtime>::get(ASSET, TREASURY), Some(35));
+        });
+    }
+
+    #[test]
+    fn transfer_does_not_commit_the_fee_when_the_destination_credit_fails() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+            set_existential_deposit(50);
+            set_fee_rate(Permill::from_percent(90));
+
+            assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                1,
+                1000
+            ));
+
+            // 90% of 200 is 180, leaving only 20 to reach the new account `2` - below the
+            // existential deposit of 50. The whole transfer must be rejected, with no fee left
+            // behind in the treasury and no balance deducted from the sender.
+            assert_noop!(
+                pallet_currency::Pallet::<Runtime>::transfer(RuntimeOrigin::signed(1), ASSET, 2, 200),
+                pallet_currency::Error::<Runtime>::ExistentialDeposit
+            );
+        });
+    }
+
+    #[test]
+    fn faucet_rejects_unapproved_accounts_when_kyc_is_enforced() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+            set_kyc_enforced(true);
+
+            assert_noop!(
+                pallet_currency::Pallet::<Runtime>::faucet(RuntimeOrigin::signed(1), ASSET),
+                pallet_currency::Error::<Runtime>::NotKycApproved
+            );
+
+            assert_ok!(pallet_currency::Pallet::<Runtime>::set_kyc(
+                RuntimeOrigin::root(),
+                1,
+                pallet_currency::KycStatus::Approved,
+            ));
+            assert_ok!(pallet_currency::Pallet::<Runtime>::faucet(RuntimeOrigin::signed(1), ASSET));
+        });
+    }
+
+    #[test]
+    fn transfer_from_does_not_burn_the_allowance_when_the_transfer_fails() {
+        TestState::new_empty().execute_with(|| {
+            create_asset(ASSET);
+
+            assert_ok!(pallet_currency::Pallet::<Runtime>::mint_unsafe(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                1,
+                50
+            ));
+            // the allowance is bigger than what `1` actually owns.
+            assert_ok!(pallet_currency::Pallet::<Runtime>::approve(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                2,
+                100
+            ));
+
+            assert_noop!(
+                pallet_currency::Pallet::<Runtime>::transfer_from(
+                    RuntimeOrigin::signed(2),
+                    ASSET,
+                    1,
+                    3,
+                    80
+                ),
+                pallet_currency::Error::<Runtime>::InsufficientBalance
+            );
+        });
+    }
+}